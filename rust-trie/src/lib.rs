@@ -1,9 +1,15 @@
 //! This is a standard implementation of a [trie](https://en.wikipedia.org/wiki/Trie) or prefix tree, data structure.
 //!
-//! No optimizations and is `O(n)` across all operations
+//! Unoptimized apart from a first-char dispatch table at the root, and is `O(n)` across all
+//! operations
+//!
+//! With the `tracing` feature enabled, [`Trie::insert`] and [`Trie::delete`] each run in a
+//! `trace` level span recording the key length (never the key itself) and the result
 
-use std::collections::VecDeque;
-use std::fmt::{Display, Formatter};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::fmt::{Debug, Display, Formatter};
+use std::str::Chars;
 
 
 #[derive(Default,Debug)]
@@ -36,25 +42,252 @@ impl Node {
             ..Default::default()
         }
     }
+
+    /// returns `true` if there are no words in this node's subtree. As `delete` prunes such
+    /// nodes, only the root can ever be empty
+    fn is_empty(&self) -> bool {
+        !self.terminal && self.children.is_empty()
+    }
+
+    /// returns the lexicographically smallest word stored in this node's subtree.
+    /// Every child holds at least one word, so this follows a single path
+    fn min_word(&self) -> Option<&str> {
+        // a word always sorts before any of its extensions
+        if self.terminal {
+            return self.value.as_deref();
+        }
+        self.children.first().and_then(|c| c.min_word())
+    }
+
+    /// returns the lexicographically largest word stored in this node's subtree.
+    /// Every child holds at least one word, so this follows a single path
+    fn max_word(&self) -> Option<&str> {
+        match self.children.last() {
+            Some(c) => c.max_word(),
+            None if self.terminal => self.value.as_deref(),
+            None => None,
+        }
+    }
+}
+
+/// the statistic [`Trie::hot_nodes`] ranks nodes by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotNodeRank {
+    /// rank by the number of direct children of a node, ties are broken by subtree size
+    Children,
+
+    /// rank by the number of words in a node's subtree, ties are broken by number of children
+    Words,
 }
 
-#[derive(Debug, Default)]
+/// fan-out statistics of a single node of a [`Trie`], as returned by [`Trie::hot_nodes`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotNode {
+    /// the prefix leading to the node, the root node has an empty prefix
+    pub prefix: String,
+
+    /// the number of direct children of the node
+    pub children: usize,
+
+    /// the number of words stored in the node's subtree, including the node itself
+    pub words: usize,
+}
+
+/// a heap entry of [`HotNodeHeap`]: the node's `(primary, secondary)` rank, its pre-order
+/// sequence number (so earlier prefixes win ties) and the child indices leading to the node
+type HotEntry = Reverse<((usize, usize), Reverse<usize>, Vec<usize>)>;
+
+/// keeps the `k` highest ranked nodes seen during a depth first walk of a trie
+struct HotNodeHeap {
+    k: usize,
+    rank: HotNodeRank,
+
+    /// min-heap of at most `k` entries, the lowest ranked entry is on top
+    heap: BinaryHeap<HotEntry>,
+
+    /// child indices leading from the root to the node being visited
+    path: Vec<usize>,
+
+    /// pre-order sequence number of the next node visited
+    seq: usize,
+}
+
+impl HotNodeHeap {
+    fn new(k: usize, rank: HotNodeRank) -> Self {
+        HotNodeHeap {
+            k,
+            rank,
+            heap: BinaryHeap::new(),
+            path: Vec::new(),
+            seq: 0,
+        }
+    }
+
+    /// visits `node` and all of its descendants, keeping any that rank in the top `k`.
+    /// returns the number of words stored in `node`'s subtree
+    fn visit(&mut self, node: &Node) -> usize {
+        let seq = self.seq;
+        self.seq += 1;
+        let mut words = usize::from(node.terminal);
+        for (idx, child) in node.children.iter().enumerate() {
+            self.path.push(idx);
+            words += self.visit(child);
+            self.path.pop();
+        }
+        let score = match self.rank {
+            HotNodeRank::Children => (node.children.len(), words),
+            HotNodeRank::Words => (words, node.children.len()),
+        };
+        // only nodes that make it into the heap have their path copied
+        let outranks_min = match self.heap.peek() {
+            Some(Reverse((min_score, min_seq, _))) => (score, Reverse(seq)) > (*min_score, *min_seq),
+            None => true,
+        };
+        if self.heap.len() < self.k || outranks_min {
+            if self.heap.len() == self.k {
+                self.heap.pop();
+            }
+            self.heap.push(Reverse((score, Reverse(seq), self.path.clone())));
+        }
+        words
+    }
+
+    /// returns the kept nodes of `root`'s trie, highest ranked first
+    fn into_hot_nodes(self, root: &Node) -> Vec<HotNode> {
+        let rank = self.rank;
+        // sorting the Reverse entries ascending puts the highest ranked node first
+        self.heap.into_sorted_vec().into_iter()
+            .map(|Reverse((score, _, path))| {
+                let mut curr = root;
+                let mut prefix = String::new();
+                for idx in path {
+                    curr = &curr.children[idx];
+                    prefix.push(curr.key.unwrap());
+                }
+                let (children, words) = match rank {
+                    HotNodeRank::Children => score,
+                    HotNodeRank::Words => (score.1, score.0),
+                };
+                HotNode { prefix, children, words }
+            })
+            .collect()
+    }
+}
+
+/// a dispatch table mapping the first char of a word directly to the index of the root's child
+/// with that key, so the root (usually the highest fan-out level of the trie) can be searched
+/// without a binary search. Only chars below `U+0100` are in the table, any other char falls back
+/// to a binary search of the root's children.
+///
+/// Each slot holds the number of root children with a smaller key, which is the index of the
+/// child with that char if there is one ([`RootIndex::OCCUPIED`] is set), or else the index it
+/// would be inserted at
+struct RootIndex([u16; 256]);
+
+impl Debug for RootIndex {
+    /// prints only the occupied slots, as `char => index`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let occupied = self.0.iter()
+            .enumerate()
+            .filter(|(_, &slot)| slot & Self::OCCUPIED != 0)
+            .map(|(c, slot)| (char::from(c as u8), slot & !Self::OCCUPIED));
+        f.debug_map().entries(occupied).finish()
+    }
+}
+
+impl RootIndex {
+    /// set on a slot whose char is the key of one of the root's children. A slot's index is at
+    /// most 255, as only chars below `U+0100` can sort before it, so it never overlaps this bit
+    const OCCUPIED: u16 = 1 << 15;
+
+    fn new() -> Self {
+        RootIndex([0; 256])
+    }
+
+    /// returns `Ok` with the index of `node`'s child with key `c`, or `Err` with the index
+    /// the child would be inserted at. `is_root` must be true when `node` is the root, so
+    /// its children are found through this table, any other node binary searches its children
+    fn child_index(&self, node: &Node, is_root: bool, c: char) -> Result<usize, usize> {
+        match self.0.get(c as usize) {
+            Some(&slot) if is_root => {
+                let idx = usize::from(slot & !Self::OCCUPIED);
+                if slot & Self::OCCUPIED != 0 { Ok(idx) } else { Err(idx) }
+            },
+            _ => node.children.binary_search_by(|f| f.key.cmp(&Some(c))),
+        }
+    }
+
+    /// inserts `child` at `idx` of `node`'s children, re-indexing the table if `node` is the
+    /// root as that shifts the index of its siblings. Returns the inserted child
+    fn insert_child<'a>(
+        &mut self,
+        node: &'a mut Node,
+        is_root: bool,
+        idx: usize,
+        child: Node,
+    ) -> &'a mut Node {
+        node.children.insert(idx, child);
+        if is_root {
+            self.rebuild(&node.children);
+        }
+        &mut node.children[idx]
+    }
+
+    /// removes the child at `idx` of `node`'s children, re-indexing the table if `node` is the
+    /// root
+    fn remove_child(&mut self, node: &mut Node, is_root: bool, idx: usize) {
+        node.children.remove(idx);
+        if is_root {
+            self.rebuild(&node.children);
+        }
+    }
+
+    /// rebuilds the table from the root's `children`
+    fn rebuild(&mut self, children: &[Node]) {
+        let mut idx = 0;
+        for (c, slot) in self.0.iter_mut().enumerate() {
+            while children.get(idx).is_some_and(|n| (n.key.unwrap() as usize) < c) {
+                idx += 1;
+            }
+            *slot = idx as u16;
+            if children.get(idx).is_some_and(|n| n.key.unwrap() as usize == c) {
+                *slot |= Self::OCCUPIED;
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
 pub struct Trie {
     root: Node,
+
+    /// dispatch table of the root's children
+    root_index: RootIndex,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Trie::new()
+    }
 }
 
 impl Trie {
     pub fn new() -> Self {
         Trie {
             root: Node::new(),
+            root_index: RootIndex::new(),
         }
     }
 
     /// inserts `s` into the trie, overwriting any previously existing values
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all, fields(len = s.len()))
+    )]
     pub fn insert(&mut self, s: &str) {
         let mut curr = &mut self.root;
-        for ch in s.chars() {
-            match curr.children.binary_search_by(|f| f.key.cmp(&Some(ch))) {
+        for (depth, ch) in s.chars().enumerate() {
+            match self.root_index.child_index(curr, depth == 0, ch) {
                 Ok(idx) => {
                     // char was found
                     // set curr to child Node and continue the traversing the Trie
@@ -62,55 +295,48 @@ impl Trie {
                 },
                 Err(idx) => {
                     // char not found, insert new node with char
-                    curr.children.insert(idx, Node::with_key(ch));
-                    curr = &mut curr.children[idx];
+                    curr = self.root_index.insert_child(curr, depth == 0, idx, Node::with_key(ch));
                 },
             }
         }
+        #[cfg(feature = "tracing")]
+        tracing::trace!(new = !curr.terminal, "inserted");
         // should be at a terminal node, set the node's value but only if it doesn't already exist
-        if curr.terminal && curr.value == Some(s.to_string()) {
-            return
-        } else {
+        if !(curr.terminal && curr.value == Some(s.to_string())) {
             curr.terminal = true;
             curr.value.replace(s.to_string());
         }
-
     }
 
     /// returns `true` if `s` exists within this trie, otherwise `false`
-    pub fn exists(&self, s: &str) -> bool {
-        let mut curr = &self.root;
-        for c in s.chars() {
-            match curr.children.binary_search_by(|f| f.key.cmp(&Some(c))) {
-                Ok(idx) => {
-                    curr = &curr.children[idx];
-                },
-                Err(_) => {
-                    return false;
-                }
-            }
-        }
+    pub fn exists(&self, s: impl AsRef<str>) -> bool {
+        self.exists_chars(s.as_ref().chars())
+    }
+
+    /// returns `true` if the word made up of `chars` exists within this trie, otherwise `false`.
+    /// Useful when the query is not stored in a contiguous `str`
+    pub fn exists_chars<I: IntoIterator<Item = char>>(&self, chars: I) -> bool {
         // check if we are at a terminal node and return true
-        curr.terminal
+        self.find(chars).is_some_and(|n| n.terminal)
     }
 
     /// returns any words in this trie that are equal to, or begin with `s`. If no words are found
     /// then an empty Vector is returned
-    pub fn search(&self, s: &str) -> Vec<String> {
-        if s.is_empty() {
+    pub fn search(&self, s: impl AsRef<str>) -> Vec<String> {
+        self.search_chars(s.as_ref().chars())
+    }
+
+    /// returns any words in this trie that are equal to, or begin with the prefix made up of
+    /// `chars`. If no words are found then an empty Vector is returned
+    pub fn search_chars<I: IntoIterator<Item = char>>(&self, chars: I) -> Vec<String> {
+        let mut chars = chars.into_iter().peekable();
+        if chars.peek().is_none() {
             return vec![];
         }
-        let mut curr = &self.root;
-        for c in s.chars() {
-            match curr.children.binary_search_by(|f| f.key.cmp(&Some(c))) {
-                Ok(idx) => {
-                    curr = &curr.children[idx];
-                },
-                Err(_) => {
-                    return Vec::new();
-                }
-            }
-        }
+        let curr = match self.find(chars) {
+            Some(n) => n,
+            None => return Vec::new(),
+        };
         // should be at end of the prefix match, need to Depth First Search and find all
         // matching nodes
         let mut matches = Vec::new();
@@ -125,43 +351,145 @@ impl Trie {
             }
         }
         // sort matches
-        matches.sort_by(|n1, n2| n2.cmp(&n1));
+        matches.sort_by(|n1, n2| n2.cmp(n1));
         matches
     }
 
+    /// returns every word in this trie that is a prefix of `s`, including `s` itself if it is
+    /// stored, ordered from shortest to longest. This is a single traversal of `s`
+    pub fn ancestors(&self, s: impl AsRef<str>) -> Vec<&str> {
+        self.ancestors_chars(s.as_ref().chars())
+    }
 
-    /// deletes `s` from the trie.
-    /// returns `true` if `s` was deleted, else `false` if `s` was not found in the trie
-    pub fn delete(&mut self, s: &str) -> bool {
-        // this is a basic delete operation in that it only decrements the terminal node count, and
-        // does actually remove the trie's internal nodes.
-        let mut curr = &mut self.root;
-        for c in s.chars() {
-            match curr.children.binary_search_by(|f| f.key.cmp(&Some(c))) {
+    /// returns every word in this trie that is a prefix of the word made up of `chars`,
+    /// ordered from shortest to longest
+    pub fn ancestors_chars<I: IntoIterator<Item = char>>(&self, chars: I) -> Vec<&str> {
+        let mut words = Vec::new();
+        if self.root.terminal {
+            words.push(self.root.value.as_deref().unwrap());
+        }
+        let mut curr = &self.root;
+        for (depth, c) in chars.into_iter().enumerate() {
+            match self.root_index.child_index(curr, depth == 0, c) {
                 Ok(idx) => {
-                    curr = &mut curr.children[idx];
+                    curr = &curr.children[idx];
                 },
                 Err(_) => {
-                    return false;
+                    break;
                 }
             }
+            if curr.terminal {
+                words.push(curr.value.as_deref().unwrap());
+            }
         }
-        // check if we are at a terminal node and decrement its count
-        if curr.terminal {
-            return match &curr.value {
-                Some(val) if val == s => {
-                    curr.terminal = false;
-                    curr.value.take();
-                    true
+        words
+    }
+
+    /// returns the lexicographically smallest word in this trie that is equal to, or begins with
+    /// `prefix`. An empty `prefix` matches every word in the trie.
+    ///
+    /// This is `O(depth)`, it follows a single path and never enumerates the subtree
+    pub fn min_with_prefix(&self, prefix: impl AsRef<str>) -> Option<&str> {
+        self.min_with_prefix_chars(prefix.as_ref().chars())
+    }
+
+    /// returns the lexicographically smallest word in this trie that is equal to, or begins with
+    /// the prefix made up of `chars`
+    pub fn min_with_prefix_chars<I: IntoIterator<Item = char>>(&self, chars: I) -> Option<&str> {
+        self.find(chars).and_then(|n| n.min_word())
+    }
+
+    /// returns the lexicographically largest word in this trie that is equal to, or begins with
+    /// `prefix`. An empty `prefix` matches every word in the trie.
+    ///
+    /// This is `O(depth)`, it follows a single path and never enumerates the subtree
+    pub fn max_with_prefix(&self, prefix: impl AsRef<str>) -> Option<&str> {
+        self.max_with_prefix_chars(prefix.as_ref().chars())
+    }
+
+    /// returns the lexicographically largest word in this trie that is equal to, or begins with
+    /// the prefix made up of `chars`
+    pub fn max_with_prefix_chars<I: IntoIterator<Item = char>>(&self, chars: I) -> Option<&str> {
+        self.find(chars).and_then(|n| n.max_word())
+    }
+
+    /// returns the Node at the end of the path made up of `chars`, or `None` if there is no
+    /// such path in the trie
+    fn find<I: IntoIterator<Item = char>>(&self, chars: I) -> Option<&Node> {
+        let mut curr = &self.root;
+        for (depth, c) in chars.into_iter().enumerate() {
+            match self.root_index.child_index(curr, depth == 0, c) {
+                Ok(idx) => {
+                    curr = &curr.children[idx];
                 },
-                _ => {
-                    false
+                Err(_) => {
+                    return None;
                 }
             }
-        } else {
-            // word was already deleted or never existed in the trie
-            false
         }
+        Some(curr)
+    }
+
+
+    /// deletes `s` from the trie.
+    /// returns `true` if `s` was deleted, else `false` if `s` was not found in the trie
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all, fields(len = s.len()), ret)
+    )]
+    pub fn delete(&mut self, s: &str) -> bool {
+        Self::delete_from(&mut self.root_index, &mut self.root, true, &mut s.chars(), s)
+    }
+
+    /// deletes `s` from the subtree of `node`, where `chars` are the remaining chars of `s`
+    /// below `node`. Any node left without words in its subtree is removed from its parent, so
+    /// every node other than the root always leads to at least one word
+    fn delete_from(
+        root_index: &mut RootIndex,
+        node: &mut Node,
+        is_root: bool,
+        chars: &mut Chars,
+        s: &str,
+    ) -> bool {
+        let c = match chars.next() {
+            Some(c) => c,
+            None => {
+                // check if we are at a terminal node holding `s`
+                return if node.terminal && node.value.as_deref() == Some(s) {
+                    node.terminal = false;
+                    node.value.take();
+                    true
+                } else {
+                    // word was already deleted or never existed in the trie
+                    false
+                };
+            },
+        };
+        let idx = match root_index.child_index(node, is_root, c) {
+            Ok(idx) => idx,
+            Err(_) => return false,
+        };
+        let deleted = Self::delete_from(root_index, &mut node.children[idx], false, chars, s);
+        if deleted && node.children[idx].is_empty() {
+            root_index.remove_child(node, is_root, idx);
+        }
+        deleted
+    }
+
+    /// returns (at most) the `k` highest ranked nodes of this trie, highest first. Nodes are
+    /// ranked by `rank`, either their number of children or the number of words in their
+    /// subtree, with the other statistic breaking ties and then the lexicographically smaller
+    /// prefix. The root node is included, with an empty prefix.
+    ///
+    /// This visits every node in the trie but only keeps `k` of them at a time, so it's
+    /// `O(n log k)` time for `n` nodes
+    pub fn hot_nodes(&self, k: usize, rank: HotNodeRank) -> Vec<HotNode> {
+        if k == 0 {
+            return vec![];
+        }
+        let mut heap = HotNodeHeap::new(k, rank);
+        heap.visit(&self.root);
+        heap.into_hot_nodes(&self.root)
     }
 }
 
@@ -197,7 +525,7 @@ impl Display for Trie {
 
 #[cfg(test)]
 mod tests {
-    use crate::Trie;
+    use crate::{HotNode, HotNodeRank, Trie};
 
     // returns a new trie with some default values
     fn new_trie() -> Trie {
@@ -229,7 +557,7 @@ mod tests {
     #[test]
     fn exists_returns_false_for_empty_trie() {
         let trie = new_trie();
-        assert_eq!(trie.exists("testing"), false);
+        assert!(!trie.exists("testing"));
     }
 
     #[test]
@@ -262,6 +590,146 @@ mod tests {
         assert_eq!(res.len(), 0);
     }
 
+    #[test]
+    fn hot_nodes_returns_highest_fan_out_first() {
+        let trie = new_trie();
+        let hot = trie.hot_nodes(3, HotNodeRank::Children);
+        assert_eq!(hot.len(), 3);
+        assert_eq!(hot[0], HotNode { prefix: "".to_string(), children: 2, words: 8 });
+        assert_eq!(hot[1], HotNode { prefix: "a".to_string(), children: 2, words: 5 });
+        assert_eq!(hot[2], HotNode { prefix: "t".to_string(), children: 2, words: 3 });
+    }
+
+    #[test]
+    fn hot_nodes_ranked_by_subtree_size() {
+        let trie = new_trie();
+        let prefixes: Vec<String> = trie.hot_nodes(4, HotNodeRank::Words)
+            .into_iter()
+            .map(|n| n.prefix)
+            .collect();
+        assert_eq!(prefixes, vec!["", "a", "t", "an"]);
+    }
+
+    #[test]
+    fn hot_nodes_bounds_k() {
+        let trie = new_trie();
+        assert!(trie.hot_nodes(0, HotNodeRank::Words).is_empty());
+        // there are 21 nodes in the trie, including the root
+        assert_eq!(trie.hot_nodes(1000, HotNodeRank::Children).len(), 21);
+        assert_eq!(trie.hot_nodes(usize::MAX, HotNodeRank::Children).len(), 21);
+    }
+
+    #[test]
+    fn exists_accepts_owned_strings_and_char_iterators() {
+        let trie = new_trie();
+        assert!(trie.exists(String::from("anna")));
+        assert!(trie.exists_chars(['t', 'e', 'a']));
+        assert!(trie.exists_chars("an".chars().chain("na".chars())));
+        assert!(!trie.exists_chars("ann".chars()));
+    }
+
+    #[test]
+    fn search_chars_matches_search() {
+        let trie = new_trie();
+        assert_eq!(trie.search_chars("te".chars()), trie.search("te"));
+        assert_eq!(trie.search_chars(std::iter::empty()).len(), 0);
+    }
+
+    #[test]
+    fn min_and_max_with_prefix() {
+        let trie = new_trie();
+        assert_eq!(trie.min_with_prefix("an"), Some("an"));
+        assert_eq!(trie.max_with_prefix("an"), Some("annabelle"));
+        assert_eq!(trie.min_with_prefix("t"), Some("tea"));
+        assert_eq!(trie.max_with_prefix("t"), Some("to"));
+        assert_eq!(trie.min_with_prefix(""), Some("a"));
+        assert_eq!(trie.max_with_prefix(""), Some("to"));
+        assert_eq!(trie.min_with_prefix("zebra"), None);
+    }
+
+    #[test]
+    fn char_iterator_variants_match_str_queries() {
+        let trie = new_trie();
+        assert_eq!(trie.ancestors_chars("ann".chars().chain("abelle".chars())), trie.ancestors("annabelle"));
+        assert_eq!(trie.min_with_prefix_chars(['t', 'e']), Some("tea"));
+        assert_eq!(trie.max_with_prefix_chars(['t', 'e']), Some("test"));
+        assert_eq!(trie.max_with_prefix_chars(std::iter::empty()), Some("to"));
+    }
+
+    #[test]
+    fn min_and_max_with_prefix_skip_deleted_words() {
+        let mut trie = new_trie();
+        trie.delete("to");
+        trie.delete("an");
+        assert_eq!(trie.max_with_prefix("t"), Some("test"));
+        assert_eq!(trie.min_with_prefix("an"), Some("anna"));
+        trie.delete("test");
+        trie.delete("tea");
+        assert_eq!(trie.max_with_prefix("t"), None);
+    }
+
+    #[test]
+    fn delete_prunes_branches_left_without_words() {
+        let mut trie = new_trie();
+        let nodes = trie.hot_nodes(1000, HotNodeRank::Children).len();
+        let words: Vec<String> = (0..10_000).map(|i| format!("b{:05}", i)).collect();
+        words.iter().for_each(|w| trie.insert(w));
+        assert_eq!(trie.max_with_prefix("b"), Some("b09999"));
+        assert!(words.iter().all(|w| trie.delete(w)));
+
+        // only the original nodes are left, so min/max walk a single path of live nodes
+        assert_eq!(trie.hot_nodes(1000, HotNodeRank::Children).len(), nodes);
+        assert_eq!(trie.min_with_prefix(""), Some("a"));
+        assert_eq!(trie.max_with_prefix(""), Some("to"));
+        assert_eq!(trie.min_with_prefix("b"), None);
+        assert!(trie.exists("annabelle"));
+    }
+
+    #[test]
+    fn delete_keeps_words_sharing_the_path() {
+        let mut trie = new_trie();
+        assert!(trie.delete("annabelle"));
+        assert!(trie.exists("anna"));
+        assert!(trie.delete("a"));
+        assert!(trie.exists("an"));
+        assert!(!trie.delete("a"));
+    }
+
+    #[test]
+    fn ancestors_returns_all_prefix_words() {
+        let trie = new_trie();
+        assert_eq!(trie.ancestors("annabelles"), vec!["a", "an", "anna", "annabelle"]);
+        assert_eq!(trie.ancestors("tea"), vec!["tea"]);
+        assert!(trie.ancestors("zebra").is_empty());
+    }
+
+    #[test]
+    fn root_index_tracks_root_children() {
+        let mut trie = Trie::new();
+        let words = ["zeta", "alpha", "ÿes", "ωmega", "mid", "\u{1F600}smile", "beta", "é"];
+        for (i, w) in words.iter().enumerate() {
+            trie.insert(w);
+            // every word inserted so far must still be found after the root's children shift
+            assert!(words[..=i].iter().all(|w| trie.exists(w)));
+        }
+        assert!(!trie.exists("gamma"));
+        assert!(!trie.exists("ωm"));
+        assert_eq!(trie.ancestors("ωmegas"), vec!["ωmega"]);
+        assert!(trie.delete("ÿes"));
+        assert!(!trie.exists("ÿes"));
+        assert!(!trie.delete("ψ"));
+    }
+
+    #[test]
+    fn debug_prints_only_occupied_root_index_slots() {
+        let mut trie = Trie::new();
+        trie.insert("to");
+        trie.insert("a");
+        let debug = format!("{:?}", trie);
+        assert!(debug.contains("root_index: {'a': 0, 't': 1}"));
+        assert!(!debug.contains(&u32::MAX.to_string()));
+    }
+
     #[test]
     fn should_delete() {
         let mut trie = Trie::new();
@@ -270,6 +738,6 @@ mod tests {
         trie.insert("tec");
         trie.delete("teb");
 
-        assert_eq!(trie.exists("teb"), false)
+        assert!(!trie.exists("teb"))
     }
 }